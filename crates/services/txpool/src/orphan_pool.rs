@@ -0,0 +1,284 @@
+use fuel_core_types::fuel_tx::{
+    Transaction,
+    TransactionBuilder,
+    TxId,
+};
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+        VecDeque,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// Bounds and timing knobs for the [`OrphanPool`].
+#[derive(Debug, Clone, Copy)]
+pub struct OrphanPoolConfig {
+    /// Maximum number of orphan transactions retained at once.
+    pub max_txs: usize,
+    /// Maximum total size, in bytes, of the retained orphan transactions.
+    pub max_bytes: usize,
+    /// How long an orphan is kept waiting for its parents before it is dropped.
+    pub ttl: Duration,
+}
+
+impl Default for OrphanPoolConfig {
+    fn default() -> Self {
+        Self {
+            max_txs: 10_000,
+            max_bytes: 10 * 1024 * 1024,
+            ttl: Duration::from_secs(10 * 60),
+        }
+    }
+}
+
+struct Orphan {
+    tx: Transaction,
+    missing: HashSet<TxId>,
+    size: usize,
+    received_at: Instant,
+}
+
+/// Holds gossiped transactions that couldn't be inserted into the pool
+/// because they reference an output or coin produced by a transaction this
+/// node hasn't seen yet. Orphans are re-submitted once every id they're
+/// waiting on has arrived, and are otherwise dropped once they exceed `ttl`
+/// or the pool's count/size bounds (oldest first).
+pub struct OrphanPool {
+    config: OrphanPoolConfig,
+    orphans: HashMap<TxId, Orphan>,
+    waiting_on: HashMap<TxId, HashSet<TxId>>,
+    insertion_order: VecDeque<TxId>,
+    total_bytes: usize,
+}
+
+impl OrphanPool {
+    pub fn new(config: OrphanPoolConfig) -> Self {
+        Self {
+            config,
+            orphans: HashMap::new(),
+            waiting_on: HashMap::new(),
+            insertion_order: VecDeque::new(),
+            total_bytes: 0,
+        }
+    }
+
+    /// Stash `tx` under `id` until every id in `missing` has been resolved.
+    /// No-op if `id` is already tracked or `missing` is empty.
+    pub fn insert(&mut self, id: TxId, tx: Transaction, missing: HashSet<TxId>) {
+        self.evict_expired();
+
+        if missing.is_empty() || self.orphans.contains_key(&id) {
+            return;
+        }
+
+        let size = tx.metered_bytes_size();
+        for parent in &missing {
+            self.waiting_on.entry(*parent).or_default().insert(id);
+        }
+        self.total_bytes = self.total_bytes.saturating_add(size);
+        self.orphans.insert(
+            id,
+            Orphan {
+                tx,
+                missing,
+                size,
+                received_at: Instant::now(),
+            },
+        );
+        self.insertion_order.push_back(id);
+
+        self.enforce_bounds();
+    }
+
+    /// Mark `available` as resolved and return every orphan that is now
+    /// fully satisfied, removed from the pool. Since promoting one orphan
+    /// can unblock another, callers should feed the id of each re-submitted
+    /// transaction back into this method.
+    pub fn resolve(&mut self, available: &TxId) -> Vec<Transaction> {
+        self.evict_expired();
+
+        let Some(waiters) = self.waiting_on.remove(available) else {
+            return Vec::new();
+        };
+
+        let mut ready = Vec::new();
+        for waiter in waiters {
+            if let Some(orphan) = self.orphans.get_mut(&waiter) {
+                orphan.missing.remove(available);
+                if orphan.missing.is_empty() {
+                    ready.push(waiter);
+                }
+            }
+        }
+
+        ready.into_iter().filter_map(|id| self.take(&id)).collect()
+    }
+
+    /// Number of orphans currently retained.
+    pub fn len(&self) -> usize {
+        self.orphans.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.orphans.is_empty()
+    }
+
+    fn take(&mut self, id: &TxId) -> Option<Transaction> {
+        let orphan = self.orphans.remove(id)?;
+        self.total_bytes = self.total_bytes.saturating_sub(orphan.size);
+        for parent in &orphan.missing {
+            if let Some(waiters) = self.waiting_on.get_mut(parent) {
+                waiters.remove(id);
+                if waiters.is_empty() {
+                    self.waiting_on.remove(parent);
+                }
+            }
+        }
+        self.insertion_order.retain(|queued| queued != id);
+        Some(orphan.tx)
+    }
+
+    fn evict_expired(&mut self) {
+        let ttl = self.config.ttl;
+        let expired: Vec<TxId> = self
+            .orphans
+            .iter()
+            .filter(|(_, orphan)| orphan.received_at.elapsed() > ttl)
+            .map(|(id, _)| *id)
+            .collect();
+        for id in expired {
+            self.take(&id);
+        }
+    }
+
+    fn enforce_bounds(&mut self) {
+        while self.orphans.len() > self.config.max_txs
+            || self.total_bytes > self.config.max_bytes
+        {
+            let Some(oldest) = self.insertion_order.pop_front() else {
+                break;
+            };
+            self.take(&oldest);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tx() -> Transaction {
+        TransactionBuilder::script(vec![], vec![]).finalize().into()
+    }
+
+    fn id(tag: u8) -> TxId {
+        TxId::from([tag; 32])
+    }
+
+    #[test]
+    fn an_orphan_is_returned_once_its_only_missing_parent_resolves() {
+        let mut pool = OrphanPool::new(OrphanPoolConfig::default());
+        pool.insert(id(1), tx(), HashSet::from([id(2)]));
+
+        let ready = pool.resolve(&id(2));
+
+        assert_eq!(ready.len(), 1);
+        assert!(pool.is_empty());
+    }
+
+    #[test]
+    fn an_orphan_waiting_on_several_parents_is_withheld_until_all_resolve() {
+        let mut pool = OrphanPool::new(OrphanPoolConfig::default());
+        pool.insert(id(1), tx(), HashSet::from([id(2), id(3)]));
+
+        assert!(pool.resolve(&id(2)).is_empty());
+        assert_eq!(pool.len(), 1);
+
+        let ready = pool.resolve(&id(3));
+        assert_eq!(ready.len(), 1);
+        assert!(pool.is_empty());
+    }
+
+    #[test]
+    fn resolving_a_parent_recursively_unblocks_a_chain_of_orphans() {
+        let mut pool = OrphanPool::new(OrphanPoolConfig::default());
+        // id(3) depends on id(2), which itself depends on id(1).
+        pool.insert(id(2), tx(), HashSet::from([id(1)]));
+        pool.insert(id(3), tx(), HashSet::from([id(2)]));
+
+        let first = pool.resolve(&id(1));
+        assert_eq!(first.len(), 1);
+
+        let second = pool.resolve(&id(2));
+        assert_eq!(second.len(), 1);
+        assert!(pool.is_empty());
+    }
+
+    #[test]
+    fn resolving_an_id_nothing_is_waiting_on_is_a_no_op() {
+        let mut pool = OrphanPool::new(OrphanPoolConfig::default());
+        pool.insert(id(1), tx(), HashSet::from([id(2)]));
+
+        let ready = pool.resolve(&id(99));
+
+        assert!(ready.is_empty());
+        assert_eq!(pool.len(), 1);
+    }
+
+    #[test]
+    fn inserting_the_same_id_twice_is_a_no_op() {
+        let mut pool = OrphanPool::new(OrphanPoolConfig::default());
+        pool.insert(id(1), tx(), HashSet::from([id(2)]));
+        pool.insert(id(1), tx(), HashSet::from([id(3)]));
+
+        // Still only waiting on the parent from the first insert.
+        assert!(pool.resolve(&id(3)).is_empty());
+        let ready = pool.resolve(&id(2));
+        assert_eq!(ready.len(), 1);
+    }
+
+    #[test]
+    fn inserting_with_no_missing_parents_is_a_no_op() {
+        let mut pool = OrphanPool::new(OrphanPoolConfig::default());
+        pool.insert(id(1), tx(), HashSet::new());
+
+        assert!(pool.is_empty());
+    }
+
+    #[test]
+    fn exceeding_max_txs_evicts_the_oldest_orphan_first() {
+        let mut pool = OrphanPool::new(OrphanPoolConfig {
+            max_txs: 1,
+            ..OrphanPoolConfig::default()
+        });
+        pool.insert(id(1), tx(), HashSet::from([id(10)]));
+        pool.insert(id(2), tx(), HashSet::from([id(11)]));
+
+        assert_eq!(pool.len(), 1);
+        // id(1) was evicted to make room, so resolving its parent now does
+        // nothing, while id(2)'s parent still unblocks it.
+        assert!(pool.resolve(&id(10)).is_empty());
+        assert_eq!(pool.resolve(&id(11)).len(), 1);
+    }
+
+    #[test]
+    fn an_orphan_past_its_ttl_is_dropped_instead_of_promoted() {
+        let mut pool = OrphanPool::new(OrphanPoolConfig {
+            ttl: Duration::from_secs(0),
+            ..OrphanPoolConfig::default()
+        });
+        pool.insert(id(1), tx(), HashSet::from([id(2)]));
+
+        // Any pool operation sweeps expired entries first.
+        std::thread::sleep(Duration::from_millis(1));
+        let ready = pool.resolve(&id(2));
+
+        assert!(ready.is_empty());
+        assert!(pool.is_empty());
+    }
+}
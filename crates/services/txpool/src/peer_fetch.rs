@@ -0,0 +1,262 @@
+use super::verification::{
+    TxOrigin,
+    VerificationQueue,
+};
+use crate::ports::PeerToPeer;
+use fuel_core_types::{
+    fuel_tx::TxId,
+    services::p2p::{
+        PeerId,
+        TransactionGossipData,
+    },
+};
+use parking_lot::Mutex as ParkingMutex;
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    sync::Arc,
+    time::Duration,
+};
+
+/// Tunables for [`PeerFetch::fetch`], split out of [`super::ServiceConfig`]
+/// so a node can tighten or loosen peer-fetch behavior without touching the
+/// other txpool subsystems' bounds.
+#[derive(Debug, Clone, Copy)]
+pub struct PeerFetchConfig {
+    /// Maximum number of distinct peers tried for a single missing-parent
+    /// fetch before giving up and leaving the child parked in the orphan
+    /// pool to resolve on its own (e.g. if the parent later arrives by
+    /// gossip).
+    pub max_peer_attempts: usize,
+    /// Upper bound on how long a single peer gets to answer a
+    /// `request_transactions` call. `PeerToPeer` implementations aren't
+    /// required to bound their own latency, so this is enforced here to
+    /// guarantee a hanging peer can't hold the [`InFlightRequests`]
+    /// reservation (and the orphaned child waiting on it) forever.
+    pub request_timeout: Duration,
+    /// Maximum number of transaction ids outstanding at once in
+    /// `request_transactions` calls to any one peer, enforced by
+    /// [`InFlightRequests`], which counts one slot per requested id.
+    pub max_in_flight_per_peer: usize,
+}
+
+impl Default for PeerFetchConfig {
+    fn default() -> Self {
+        Self {
+            max_peer_attempts: 3,
+            request_timeout: Duration::from_secs(10),
+            max_in_flight_per_peer: 8,
+        }
+    }
+}
+
+/// Tracks outstanding `request_transactions` calls so the same missing id
+/// isn't requested repeatedly while a request for it is already in flight,
+/// and so a single peer can't be sent an unbounded number of concurrent
+/// requests.
+pub struct InFlightRequests {
+    max_per_peer: usize,
+    outstanding_per_peer: HashMap<PeerId, usize>,
+    requested_ids: HashSet<TxId>,
+}
+
+impl InFlightRequests {
+    pub fn new(max_per_peer: usize) -> Self {
+        Self {
+            max_per_peer,
+            outstanding_per_peer: HashMap::new(),
+            requested_ids: HashSet::new(),
+        }
+    }
+
+    /// Filter `ids` down to the ones that aren't already being fetched from
+    /// any peer, reserving a slot against `peer`'s concurrency cap for each
+    /// one kept. Stops reserving once `peer` is at capacity.
+    pub fn reserve(&mut self, peer: &PeerId, ids: impl IntoIterator<Item = TxId>) -> Vec<TxId> {
+        let outstanding = self.outstanding_per_peer.entry(peer.clone()).or_insert(0);
+        let mut reserved = Vec::new();
+        for id in ids {
+            if *outstanding >= self.max_per_peer {
+                break;
+            }
+            if self.requested_ids.insert(id) {
+                *outstanding += 1;
+                reserved.push(id);
+            }
+        }
+        reserved
+    }
+
+    /// Release the slots reserved for `ids` against `peer`, whether the
+    /// request succeeded, failed, or timed out.
+    pub fn release(&mut self, peer: &PeerId, ids: &[TxId]) {
+        if let Some(outstanding) = self.outstanding_per_peer.get_mut(peer) {
+            *outstanding = outstanding.saturating_sub(ids.len());
+        }
+        for id in ids {
+            self.requested_ids.remove(id);
+        }
+    }
+}
+
+/// What's needed to pull a missing parent from the network once insertion
+/// reports that an unknown input is the actual cause of rejection. Bundled
+/// together so call sites that don't have a peer to ask (orphan promotion,
+/// locally-submitted transactions) don't need to thread these through.
+pub(crate) struct PeerFetch<P2P> {
+    pub p2p: Arc<P2P>,
+    pub in_flight: Arc<ParkingMutex<InFlightRequests>>,
+    pub verification_queue: VerificationQueue,
+    pub config: PeerFetchConfig,
+}
+
+// Written by hand instead of `#[derive(Clone)]`: every field is already
+// cheap to clone through its own `Arc` or, for `config`, `Copy`, but a
+// derived impl would add a spurious `P2P: Clone` bound that most
+// `PeerToPeer` implementations don't (and shouldn't need to) satisfy.
+impl<P2P> Clone for PeerFetch<P2P> {
+    fn clone(&self) -> Self {
+        Self {
+            p2p: self.p2p.clone(),
+            in_flight: self.in_flight.clone(),
+            verification_queue: self.verification_queue.clone(),
+            config: self.config,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn peer(tag: u8) -> PeerId {
+        PeerId::from(vec![tag])
+    }
+
+    #[test]
+    fn reserving_a_fresh_id_succeeds_and_counts_against_the_peer() {
+        let mut requests = InFlightRequests::new(2);
+        let id = TxId::from([1u8; 32]);
+
+        let reserved = requests.reserve(&peer(1), [id]);
+
+        assert_eq!(reserved, vec![id]);
+        assert_eq!(requests.outstanding_per_peer[&peer(1)], 1);
+    }
+
+    #[test]
+    fn an_id_already_in_flight_for_any_peer_is_not_reserved_again() {
+        let mut requests = InFlightRequests::new(2);
+        let id = TxId::from([1u8; 32]);
+        requests.reserve(&peer(1), [id]);
+
+        let reserved = requests.reserve(&peer(2), [id]);
+
+        assert!(reserved.is_empty());
+    }
+
+    #[test]
+    fn reservation_stops_once_a_peer_is_at_its_concurrency_cap() {
+        let mut requests = InFlightRequests::new(1);
+        let first = TxId::from([1u8; 32]);
+        let second = TxId::from([2u8; 32]);
+
+        let reserved = requests.reserve(&peer(1), [first, second]);
+
+        assert_eq!(reserved, vec![first]);
+    }
+
+    #[test]
+    fn releasing_frees_the_peers_slots_and_lets_the_id_be_reserved_again() {
+        let mut requests = InFlightRequests::new(1);
+        let id = TxId::from([1u8; 32]);
+        requests.reserve(&peer(1), [id]);
+
+        requests.release(&peer(1), &[id]);
+
+        let reserved = requests.reserve(&peer(1), [id]);
+        assert_eq!(reserved, vec![id]);
+    }
+}
+
+impl<P2P> PeerFetch<P2P>
+where
+    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData> + 'static,
+{
+    /// Actively pull `missing` transaction ids, starting with `source` (the
+    /// peer that gossiped the child depending on them) and falling back to
+    /// another connected peer if `source` errors or doesn't answer within
+    /// `self.config.request_timeout`, up to `self.config.max_peer_attempts`
+    /// distinct peers total. Anything a peer has is fed back into the
+    /// verification queue as if it had arrived by gossip.
+    pub fn fetch(&self, source: PeerId, missing: HashSet<TxId>) {
+        let ids = self.in_flight.lock().reserve(&source, missing);
+        if ids.is_empty() {
+            return;
+        }
+
+        let p2p = self.p2p.clone();
+        let in_flight = self.in_flight.clone();
+        let verification_queue = self.verification_queue.clone();
+        let config = self.config;
+
+        tokio::spawn(async move {
+            let mut tried = HashSet::from([source.clone()]);
+            let mut peer = source;
+            let mut remaining = ids;
+
+            for attempt in 0..config.max_peer_attempts {
+                let result = tokio::time::timeout(
+                    config.request_timeout,
+                    p2p.request_transactions(peer.clone(), remaining.clone()),
+                )
+                .await;
+                in_flight.lock().release(&peer, &remaining);
+
+                // Flatten the timeout into the same `Err` arm as an outright
+                // request failure below: a peer that's too slow is treated no
+                // differently than one that errored outright, either way the
+                // reservation above has already been released so it can't
+                // leak, and the next attempt (if any) tries a different peer.
+                let result = match result {
+                    Ok(result) => result,
+                    Err(_) => Err(anyhow::anyhow!(
+                        "request_transactions timed out after {:?}",
+                        config.request_timeout
+                    )),
+                };
+
+                match result {
+                    Ok(txs) => {
+                        for tx in txs.into_iter().flatten() {
+                            let _ = verification_queue.enqueue(tx, TxOrigin::Gossip(peer.clone()));
+                        }
+                        return;
+                    }
+                    // Treat an error the same whether it's an outright
+                    // failure or a timeout surfaced as one; the timeout was
+                    // flattened into this same `Err` above.
+                    Err(_) if attempt + 1 < config.max_peer_attempts => {
+                        let Some(next) = p2p
+                            .connected_peers()
+                            .into_iter()
+                            .find(|candidate| !tried.contains(candidate))
+                        else {
+                            return;
+                        };
+                        let reserved = in_flight.lock().reserve(&next, remaining.iter().copied());
+                        if reserved.is_empty() {
+                            return;
+                        }
+                        tried.insert(next.clone());
+                        peer = next;
+                        remaining = reserved;
+                    }
+                    Err(_) => return,
+                }
+            }
+        });
+    }
+}
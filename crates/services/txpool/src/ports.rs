@@ -0,0 +1,44 @@
+use fuel_core_services::stream::BoxStream;
+use fuel_core_types::{
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
+    services::{
+        block_importer::ImportResult,
+        p2p::PeerId,
+    },
+};
+use std::sync::Arc;
+
+pub trait BlockImporter: Send + Sync {
+    /// Streams the result of every block the node commits, including ones
+    /// produced by sync instead of local block production.
+    fn block_events(&self) -> BoxStream<Arc<ImportResult>>;
+}
+
+#[async_trait::async_trait]
+pub trait PeerToPeer: Send + Sync {
+    type GossipedTransaction: Send + Sync + 'static;
+
+    /// Broadcast a transaction to the network.
+    fn broadcast_transaction(&self, transaction: Arc<Transaction>) -> anyhow::Result<()>;
+
+    fn gossiped_transaction_events(&self) -> BoxStream<Self::GossipedTransaction>;
+
+    /// Ask `peer` directly for the given transactions, e.g. to recover a
+    /// gossiped transaction's parents that haven't independently arrived.
+    /// Each entry in the returned `Vec` lines up by index with `tx_ids`;
+    /// `None` means the peer didn't have that transaction.
+    async fn request_transactions(
+        &self,
+        peer: PeerId,
+        tx_ids: Vec<TxId>,
+    ) -> anyhow::Result<Vec<Option<Transaction>>>;
+
+    /// Currently connected peers, used to retry a `request_transactions`
+    /// call against a different peer once the first one errors or times out.
+    fn connected_peers(&self) -> Vec<PeerId>;
+}
+
+pub trait TxPoolDb: Send + Sync {}
@@ -0,0 +1,144 @@
+use std::fmt;
+
+/// Structured reasons a transaction can be rejected at insertion time,
+/// distinct from [`fuel_core_types::services::txpool::TxStatus::SqueezedOut`],
+/// which covers a transaction the pool had already accepted being evicted
+/// later (e.g. to make room for a higher-fee transaction).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum RejectionReason {
+    /// Transaction signature or predicate verification failed.
+    InvalidSignature,
+    /// An input conflicts with a transaction already in the pool.
+    ConflictingInput,
+    /// The offered gas price is below the pool's minimum.
+    FeeTooLow,
+    /// The pool is full and this transaction didn't outbid the cheapest entry.
+    PoolFull,
+    /// An input spends a coin or contract output produced by a transaction
+    /// this node hasn't seen yet. Distinct from the other variants: this is
+    /// the only reason that should ever send a transaction to the
+    /// [`OrphanPool`](crate::service::orphan_pool::OrphanPool) instead of
+    /// being reported rejected outright.
+    UnknownInput,
+    /// Any other rejection, carrying the underlying error message.
+    Other(String),
+}
+
+impl fmt::Display for RejectionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSignature => write!(f, "invalid transaction signature"),
+            Self::ConflictingInput => {
+                write!(f, "input conflicts with a transaction already in the pool")
+            }
+            Self::FeeTooLow => write!(f, "gas price is below the pool minimum"),
+            Self::PoolFull => write!(f, "pool is full"),
+            Self::UnknownInput => {
+                write!(f, "spends an input from a transaction not yet seen")
+            }
+            Self::Other(reason) => write!(f, "{reason}"),
+        }
+    }
+}
+
+impl RejectionReason {
+    /// Best-effort classification of an insertion error into a structured
+    /// reason, used until the pool's own error type exposes the cause
+    /// directly. Falls back to [`RejectionReason::Other`] with the error's
+    /// message when the cause can't be pinpointed.
+    ///
+    /// Classifies on `crate::Error`'s own message once downcast out of
+    /// `error`, rather than `error`'s full `Display` chain: `error` is an
+    /// `anyhow::Error` that may have picked up additional `.context()`
+    /// layers on its way up from `TxPool::insert`, and matching against that
+    /// whole chain risked an unrelated context string steering the
+    /// classification -- the actual cause of this heuristic's two prior
+    /// regressions. `crate::Error`'s variants aren't matched directly; only
+    /// its message is classified.
+    ///
+    /// Checked ahead of the other variants: an error can simultaneously
+    /// mention a missing input *and*, say, a fee, so unknown-input has to
+    /// win the classification, since it's the only reason callers should
+    /// treat as "try again once the parent arrives" rather than a final
+    /// rejection. Unlike the earlier version of this check, the
+    /// unknown-input match requires the message to actually name the thing
+    /// that's missing (a utxo/contract/input), not just contain a bare word
+    /// like "missing" or "unknown" that a signature or duplicate-spend
+    /// error could just as easily use.
+    pub fn from_insert_error(error: &anyhow::Error) -> Self {
+        let message = error
+            .downcast_ref::<crate::Error>()
+            .map(ToString::to_string)
+            .unwrap_or_else(|| error.to_string());
+        let lower = message.to_lowercase();
+
+        let names_missing_dependency = lower.contains("utxo")
+            || lower.contains("contract")
+            || lower.contains("dependency")
+            || lower.contains("input");
+        let not_yet_seen = lower.contains("not exist")
+            || lower.contains("not found")
+            || lower.contains("unknown")
+            || lower.contains("has not been seen");
+
+        if names_missing_dependency && not_yet_seen {
+            Self::UnknownInput
+        } else if lower.contains("signature") {
+            Self::InvalidSignature
+        } else if lower.contains("conflict")
+            || lower.contains("collision")
+            || lower.contains("already spent")
+            || lower.contains("double")
+        {
+            Self::ConflictingInput
+        } else if lower.contains("gas price") || lower.contains("fee") {
+            Self::FeeTooLow
+        } else if lower.contains("full") || lower.contains("limit") {
+            Self::PoolFull
+        } else {
+            Self::Other(message)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classify(message: &str) -> RejectionReason {
+        RejectionReason::from_insert_error(&anyhow::anyhow!(message.to_string()))
+    }
+
+    #[test]
+    fn unknown_input_requires_both_a_named_dependency_and_not_yet_seen_phrasing() {
+        assert_eq!(
+            classify("utxo does not exist: 0x00"),
+            RejectionReason::UnknownInput
+        );
+        assert_eq!(
+            classify("input contract is unknown"),
+            RejectionReason::UnknownInput
+        );
+    }
+
+    #[test]
+    fn a_missing_signature_is_not_misread_as_an_unknown_input() {
+        assert_eq!(classify("missing signature data"), RejectionReason::InvalidSignature);
+    }
+
+    #[test]
+    fn an_already_spent_utxo_is_a_conflict_not_an_unknown_input() {
+        assert_eq!(
+            classify("utxo already spent"),
+            RejectionReason::ConflictingInput
+        );
+    }
+
+    #[test]
+    fn an_unrecognised_message_falls_back_to_other() {
+        assert_eq!(
+            classify("database is unavailable"),
+            RejectionReason::Other("database is unavailable".to_string())
+        );
+    }
+}
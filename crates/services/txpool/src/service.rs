@@ -1,3 +1,24 @@
+use self::{
+    orphan_pool::{
+        OrphanPool,
+        OrphanPoolConfig,
+    },
+    peer_fetch::{
+        InFlightRequests,
+        PeerFetch,
+        PeerFetchConfig,
+    },
+    rejection_reason::RejectionReason,
+    tx_cache::{
+        TxVerificationCache,
+        VerificationOutcome,
+    },
+    verification::{
+        self,
+        TxOrigin,
+        VerificationQueue,
+    },
+};
 use crate::{
     ports::{
         BlockImporter,
@@ -18,80 +39,231 @@ use fuel_core_services::{
 };
 use fuel_core_types::{
     fuel_tx::{
+        input::Input,
+        ChainId,
         Transaction,
         TxId,
+        UniqueIdentifier,
     },
     fuel_types::Bytes32,
     services::{
         block_importer::ImportResult,
         p2p::{
             GossipData,
+            PeerId,
             TransactionGossipData,
         },
         txpool::{
             ArcPoolTx,
-            InsertionResult,
             TxInfo,
             TxStatus,
         },
     },
 };
 use parking_lot::Mutex as ParkingMutex;
-use std::sync::Arc;
+use std::{
+    collections::HashSet,
+    sync::Arc,
+};
 use tokio::sync::broadcast;
 use tokio_stream::StreamExt;
 
+/// Collects the ids of the transactions that produced the coins `tx`
+/// spends, so callers can tell which still-missing parents a rejected
+/// transaction is waiting on.
+fn parent_ids(tx: &Transaction) -> HashSet<TxId> {
+    tx.inputs()
+        .iter()
+        .filter_map(Input::utxo_id)
+        .map(|utxo_id| *utxo_id.tx_id())
+        .collect()
+}
+
 pub type Service<P2P, DB> = ServiceRunner<Task<P2P, DB>>;
 
+/// Every lifecycle transition this pool reports for a transaction.
+///
+/// Wraps the upstream [`TxStatus`], whose variants are defined in
+/// `fuel-core-types` and can't be extended from this crate, together with
+/// the two states this crate adds on top: a transaction
+/// can't be represented as a `TxStatus::Proposed`/`TxStatus::Rejected`
+/// without either a dependency cycle (`RejectionReason` lives here, in
+/// `txpool`, which already depends on `fuel-core-types`) or a matching
+/// change upstream, so they're reported as their own variants instead.
+#[derive(Debug, Clone)]
+pub enum TxLifecycle {
+    /// A status defined upstream in `fuel-core-types`.
+    Status(TxStatus),
+    /// A transaction was selected into a block template by
+    /// `select_transactions`, but hasn't been committed yet.
+    Proposed,
+    /// A transaction was rejected at insertion time (bad signature,
+    /// conflicting input, fee too low, pool full, ...), as opposed to
+    /// [`TxStatus::SqueezedOut`] which covers an already-accepted
+    /// transaction being evicted later.
+    Rejected(RejectionReason),
+}
+
+/// Callback embedders can register with [`new_service`] to observe every tx
+/// lifecycle transition directly, without subscribing to the broadcast
+/// channel (e.g. to plug in metrics or an index).
+pub type TxStatusCallback = Arc<dyn Fn(Bytes32, &TxLifecycle) + Send + Sync>;
+
 #[derive(Clone)]
 pub struct TxStatusChange {
-    status_sender: broadcast::Sender<TxStatus>,
+    status_sender: broadcast::Sender<TxLifecycle>,
     update_sender: broadcast::Sender<TxUpdate>,
+    callback: Option<TxStatusCallback>,
 }
 
 impl TxStatusChange {
-    pub fn new(capacity: usize) -> Self {
+    pub fn new(capacity: usize, callback: Option<TxStatusCallback>) -> Self {
         let (status_sender, _) = broadcast::channel(capacity);
         let (update_sender, _) = broadcast::channel(capacity);
         Self {
             status_sender,
             update_sender,
+            callback,
         }
     }
 
     pub fn send_complete(&self, id: Bytes32) {
-        let _ = self.status_sender.send(TxStatus::Completed);
-        self.updated(id);
+        self.notify(id, TxLifecycle::Status(TxStatus::Completed));
     }
 
     pub fn send_submitted(&self, id: Bytes32) {
-        let _ = self.status_sender.send(TxStatus::Submitted);
-        self.updated(id);
+        self.notify(id, TxLifecycle::Status(TxStatus::Submitted));
+    }
+
+    pub fn send_proposed(&self, id: Bytes32) {
+        self.notify(id, TxLifecycle::Proposed);
+    }
+
+    pub fn send_rejected(&self, id: Bytes32, reason: RejectionReason) {
+        self.notify(id, TxLifecycle::Rejected(reason));
     }
 
     pub fn send_squeezed_out(&self, id: Bytes32, reason: TxPoolError) {
-        let _ = self.status_sender.send(TxStatus::SqueezedOut {
-            reason: reason.clone(),
-        });
+        self.notify(
+            id,
+            TxLifecycle::Status(TxStatus::SqueezedOut {
+                reason: reason.clone(),
+            }),
+        );
         let _ = self.update_sender.send(TxUpdate::squeezed_out(id, reason));
     }
 
+    fn notify(&self, id: Bytes32, status: TxLifecycle) {
+        let _ = self.status_sender.send(status.clone());
+        if let Some(callback) = &self.callback {
+            callback(id, &status);
+        }
+        self.updated(id);
+    }
+
     fn updated(&self, id: Bytes32) {
         let _ = self.update_sender.send(TxUpdate::updated(id));
     }
 }
 
+#[cfg(test)]
+mod tx_status_change_tests {
+    use super::*;
+
+    fn recorder() -> (TxStatusCallback, Arc<ParkingMutex<Vec<(Bytes32, TxLifecycle)>>>) {
+        let calls = Arc::new(ParkingMutex::new(Vec::new()));
+        let recorded = calls.clone();
+        let callback: TxStatusCallback = Arc::new(move |id, lifecycle| {
+            recorded.lock().push((id, lifecycle.clone()));
+        });
+        (callback, calls)
+    }
+
+    #[test]
+    fn send_submitted_reports_the_upstream_submitted_status() {
+        let (callback, calls) = recorder();
+        let change = TxStatusChange::new(1, Some(callback));
+        let id = Bytes32::from([1u8; 32]);
+
+        change.send_submitted(id);
+
+        let calls = calls.lock();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].0, id);
+        assert!(matches!(
+            calls[0].1,
+            TxLifecycle::Status(TxStatus::Submitted)
+        ));
+    }
+
+    #[test]
+    fn send_proposed_reports_the_local_proposed_variant() {
+        let (callback, calls) = recorder();
+        let change = TxStatusChange::new(1, Some(callback));
+        let id = Bytes32::from([1u8; 32]);
+
+        change.send_proposed(id);
+
+        assert!(matches!(calls.lock()[0].1, TxLifecycle::Proposed));
+    }
+
+    #[test]
+    fn send_rejected_carries_the_rejection_reason() {
+        let (callback, calls) = recorder();
+        let change = TxStatusChange::new(1, Some(callback));
+        let id = Bytes32::from([1u8; 32]);
+
+        change.send_rejected(id, RejectionReason::FeeTooLow);
+
+        match &calls.lock()[0].1 {
+            TxLifecycle::Rejected(reason) => assert_eq!(*reason, RejectionReason::FeeTooLow),
+            other => panic!("expected Rejected, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn every_notification_is_also_published_on_the_broadcast_channel() {
+        let change = TxStatusChange::new(4, None);
+        let mut status_rx = change.status_sender.subscribe();
+        let id = Bytes32::from([1u8; 32]);
+
+        change.send_complete(id);
+
+        let received = status_rx.try_recv().expect("a status was broadcast");
+        assert!(matches!(
+            received,
+            TxLifecycle::Status(TxStatus::Completed)
+        ));
+    }
+
+    #[test]
+    fn without_a_callback_registered_notification_still_succeeds() {
+        let change = TxStatusChange::new(1, None);
+        change.send_submitted(Bytes32::from([1u8; 32]));
+    }
+}
+
 pub struct SharedState<P2P, DB> {
+    chain_id: ChainId,
     tx_status_sender: TxStatusChange,
     txpool: Arc<ParkingMutex<TxPool<DB>>>,
+    orphan_pool: Arc<ParkingMutex<OrphanPool>>,
+    tx_cache: Arc<ParkingMutex<TxVerificationCache>>,
+    verification_queue: VerificationQueue,
+    peer_fetch: PeerFetch<P2P>,
     p2p: Arc<P2P>,
 }
 
 impl<P2P, DB> Clone for SharedState<P2P, DB> {
     fn clone(&self) -> Self {
         Self {
+            chain_id: self.chain_id,
             tx_status_sender: self.tx_status_sender.clone(),
             txpool: self.txpool.clone(),
+            orphan_pool: self.orphan_pool.clone(),
+            tx_cache: self.tx_cache.clone(),
+            verification_queue: self.verification_queue.clone(),
+            peer_fetch: self.peer_fetch.clone(),
             p2p: self.p2p.clone(),
         }
     }
@@ -126,22 +298,30 @@ where
 #[async_trait::async_trait]
 impl<P2P, DB> RunnableTask for Task<P2P, DB>
 where
-    P2P: Send + Sync,
+    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData> + 'static,
     DB: TxPoolDb,
 {
     async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
         let should_continue;
         tokio::select! {
             _ = watcher.while_started() => {
+                // The verification worker is spawned independently of this
+                // task and outlives it otherwise: `VerificationQueue` is
+                // cloned into every `SharedState` handle, including ones
+                // handed out through `shared_data()` (e.g. to GraphQL), so
+                // nothing else would ever drop the last sender and let the
+                // worker's loop end on its own.
+                self.shared.verification_queue.stop();
                 should_continue = false;
             }
             new_transaction = self.gossiped_tx_stream.next() => {
-                if let Some(GossipData { data: Some(tx), .. }) = new_transaction {
-                    let txs = vec!(Arc::new(tx));
-                    self.shared.txpool.lock().insert(
-                        &self.shared.tx_status_sender,
-                        &txs
-                    );
+                if let Some(GossipData { data: Some(tx), peer_id, .. }) = new_transaction {
+                    if let Err(tx) = self.shared.verification_queue.enqueue(tx, TxOrigin::Gossip(peer_id)) {
+                        tracing::warn!(
+                            "Verification queue is full or stopped, dropping gossiped transaction {:?}",
+                            tx.id(&self.shared.chain_id)
+                        );
+                    }
                     should_continue = true;
                 } else {
                     should_continue = false;
@@ -151,6 +331,16 @@ where
             result = self.committed_block_stream.next() => {
                 if let Some(result) = result {
                     self.shared.txpool.lock().block_update(&self.shared.tx_status_sender, &result.sealed_block);
+                    // Consensus parameters can only change at a block boundary, so
+                    // this is the only place a version bump can originate, but most
+                    // blocks don't actually carry an upgrade; only invalidate the
+                    // cache when the version has actually moved.
+                    self.shared.tx_cache.lock().observe_consensus_parameters_version(
+                        result.sealed_block.entity.header().consensus_parameters_version(),
+                    );
+                    let chain_id = self.shared.chain_id;
+                    let newly_available = result.sealed_block.entity.transactions().iter().map(move |tx| tx.id(&chain_id));
+                    self.shared.promote_orphans(newly_available);
                     should_continue = true;
                 } else {
                     should_continue = false;
@@ -167,6 +357,7 @@ where
 //  `StorageInspect` trait.
 impl<P2P, DB> SharedState<P2P, DB>
 where
+    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData> + 'static,
     DB: TxPoolDb,
 {
     pub fn pending_number(&self) -> usize {
@@ -177,6 +368,38 @@ where
         self.txpool.lock().consumable_gas()
     }
 
+    /// Number of transactions currently parked in the orphan pool, waiting
+    /// on a parent that hasn't arrived yet.
+    pub fn orphan_pool_len(&self) -> usize {
+        self.orphan_pool.lock().len()
+    }
+
+    /// Number of transactions currently waiting to be verified. Operators
+    /// can watch this to detect verification backpressure.
+    pub fn verification_queue_len(&self) -> usize {
+        self.verification_queue.len()
+    }
+
+    /// Discard every cached verification verdict, e.g. to force
+    /// re-validation after loading a new consensus parameters set out of
+    /// band.
+    pub fn clear_verification_cache(&self) {
+        self.tx_cache.lock().clear();
+    }
+
+    /// Pause the verification worker, e.g. while a heavy block import or
+    /// resync is in progress. Queued and newly gossiped transactions stay
+    /// buffered, up to the queue's capacity, until
+    /// [`Self::resume_verification`] is called; once the queue is full,
+    /// further transactions are handed back by the enqueue and not buffered.
+    pub fn suspend_verification(&self) {
+        self.verification_queue.suspend();
+    }
+
+    pub fn resume_verification(&self) {
+        self.verification_queue.resume();
+    }
+
     pub fn remove_txs(&self, ids: Vec<TxId>) -> Vec<ArcPoolTx> {
         self.txpool.lock().remove(&self.tx_status_sender, &ids)
     }
@@ -200,6 +423,7 @@ where
 
         for tx in sorted_txs.iter() {
             guard.remove_committed_tx(&tx.id());
+            self.tx_status_sender.send_proposed(tx.id());
         }
         sorted_txs
     }
@@ -208,42 +432,190 @@ where
         self.txpool.lock().remove(&self.tx_status_sender, &ids)
     }
 
-    pub fn tx_status_subscribe(&self) -> broadcast::Receiver<TxStatus> {
+    pub fn tx_status_subscribe(&self) -> broadcast::Receiver<TxLifecycle> {
         self.tx_status_sender.status_sender.subscribe()
     }
 
     pub fn tx_update_subscribe(&self) -> broadcast::Receiver<TxUpdate> {
         self.tx_status_sender.update_sender.subscribe()
     }
+
+    /// Cached verification verdict for `id`, including the fee/gas metadata
+    /// computed when it was accepted (see [`VerificationOutcome::Accepted`]).
+    /// `None` if `id` hasn't been verified yet, or was verified under a
+    /// since-superseded consensus parameters version.
+    pub fn verification_outcome(&self, id: TxId) -> Option<VerificationOutcome> {
+        self.tx_cache.lock().get(&id)
+    }
+
+    fn promote_orphans(&self, newly_available: impl Iterator<Item = TxId>) {
+        verification::promote_orphans(
+            &self.chain_id,
+            &self.txpool,
+            &self.orphan_pool,
+            &self.tx_cache,
+            &self.tx_status_sender,
+            &self.peer_fetch,
+            newly_available,
+        );
+    }
 }
 
-impl<P2P, DB> SharedState<P2P, DB>
+/// Core of [`process_verified`]: consult the cache, try inserting `tx`, and
+/// report/cache/re-orphan the outcome. Deliberately doesn't cascade into
+/// [`verification::promote_orphans`] itself — [`promote_orphans`] drives
+/// that iteratively over however many orphans a single acceptance unblocks,
+/// so that a long dependency chain doesn't grow the call stack. Returns the
+/// id of `tx` if it is accepted into the pool (whether newly or on a cached
+/// acceptance), so callers that need to cascade can decide how to.
+///
+/// Consults `tx_cache` first, for either outcome: a cached rejection lets an
+/// invalid, re-offered transaction be dropped without touching the pool at
+/// all, and a cached acceptance skips re-verification and re-insertion
+/// entirely for a transaction that's already been through this once,
+/// whether it's still actually sitting in the pool or was since evicted —
+/// the cache only remembers the verification verdict, not pool membership.
+///
+/// `origin` (see [`TxOrigin`]) decides who to ask for a missing parent if
+/// insertion fails on a genuinely unknown input, and whether a successful
+/// insertion should be rebroadcast to the network.
+pub(crate) fn insert_or_reject<P2P, DB>(
+    chain_id: &ChainId,
+    txpool: &Arc<ParkingMutex<TxPool<DB>>>,
+    orphan_pool: &Arc<ParkingMutex<OrphanPool>>,
+    tx_cache: &Arc<ParkingMutex<TxVerificationCache>>,
+    tx_status_sender: &TxStatusChange,
+    tx: Transaction,
+    origin: &TxOrigin,
+    peer_fetch: &PeerFetch<P2P>,
+) -> Option<TxId>
 where
-    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData>,
+    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData> + 'static,
     DB: TxPoolDb,
 {
-    pub fn insert(
-        &self,
-        txs: Vec<Arc<Transaction>>,
-    ) -> Vec<anyhow::Result<InsertionResult>> {
-        let insert = { self.txpool.lock().insert(&self.tx_status_sender, &txs) };
-
-        for (ret, tx) in insert.iter().zip(txs.into_iter()) {
-            match ret {
-                Ok(_) => {
-                    let result = self.p2p.broadcast_transaction(tx.clone());
-                    if let Err(e) = result {
-                        // It can be only in the case of p2p being down or requests overloading it.
-                        tracing::error!(
-                            "Unable to broadcast transaction, got an {} error",
-                            e
-                        );
-                    }
+    let id = tx.id(chain_id);
+
+    match tx_cache.lock().get(&id) {
+        Some(VerificationOutcome::Rejected(reason)) => {
+            tx_status_sender.send_rejected(id, reason);
+            return None;
+        }
+        // Already verified and accepted once; don't pay for re-verification
+        // just because it was re-offered (e.g. re-gossiped, or resubmitted by
+        // a client that didn't see the first acceptance). We don't know
+        // whether it's still actually in the pool (it may have since been
+        // evicted), so report the id back the same as a fresh acceptance
+        // would, without re-touching the pool or rebroadcasting.
+        Some(VerificationOutcome::Accepted { .. }) => return Some(id),
+        None => {}
+    }
+
+    let txs = vec![Arc::new(tx)];
+    let results = txpool.lock().insert(tx_status_sender, &txs);
+
+    match results.into_iter().next() {
+        Some(Ok(inserted)) => {
+            let gas = inserted.max_gas();
+            // `price() * max_gas()` can overflow `u64`; widen to `u128` for
+            // the multiplication (matching `transaction_selector`'s own
+            // package-fee computation) and saturate back down rather than
+            // panicking on a pathological price/gas combination.
+            let fee = (inserted.price() as u128 * gas as u128).min(u64::MAX as u128) as u64;
+            tx_cache
+                .lock()
+                .insert(id, VerificationOutcome::Accepted { fee, gas });
+            if origin.should_broadcast() {
+                if let Err(e) = peer_fetch.p2p.broadcast_transaction(txs[0].clone()) {
+                    // It can be only in the case of p2p being down or requests overloading it.
+                    tracing::error!("Unable to broadcast transaction, got an {} error", e);
                 }
-                Err(_) => {}
             }
+            Some(id)
         }
-        insert
+        Some(Err(err)) => {
+            let reason = RejectionReason::from_insert_error(&err);
+            let missing = parent_ids(&txs[0]);
+            if reason == RejectionReason::UnknownInput && !missing.is_empty() {
+                if let Some(peer) = origin.peer() {
+                    peer_fetch.fetch(peer, missing.clone());
+                }
+                let tx = Arc::try_unwrap(txs.into_iter().next().expect("txs has one element"))
+                    .unwrap_or_else(|arc| (*arc).clone());
+                orphan_pool.lock().insert(id, tx, missing);
+            } else {
+                tx_cache
+                    .lock()
+                    .insert(id, VerificationOutcome::Rejected(reason.clone()));
+                tx_status_sender.send_rejected(id, reason);
+            }
+            None
+        }
+        None => None,
+    }
+}
+
+/// Insert a verified transaction, diverting it into the [`OrphanPool`]
+/// instead of immediately reporting it `Rejected` when it only fails
+/// because it references a coin or output produced by a transaction this
+/// node hasn't seen yet, then promote whatever orphans that unblocks. Used
+/// by the verification worker for every gossiped and locally-submitted
+/// transaction (see [`SharedState::insert`]).
+pub(crate) fn process_verified<P2P, DB>(
+    chain_id: &ChainId,
+    txpool: &Arc<ParkingMutex<TxPool<DB>>>,
+    orphan_pool: &Arc<ParkingMutex<OrphanPool>>,
+    tx_cache: &Arc<ParkingMutex<TxVerificationCache>>,
+    tx_status_sender: &TxStatusChange,
+    tx: Transaction,
+    origin: TxOrigin,
+    peer_fetch: &PeerFetch<P2P>,
+) where
+    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData> + 'static,
+    DB: TxPoolDb,
+{
+    if let Some(id) = insert_or_reject(
+        chain_id,
+        txpool,
+        orphan_pool,
+        tx_cache,
+        tx_status_sender,
+        tx,
+        &origin,
+        peer_fetch,
+    ) {
+        verification::promote_orphans(
+            chain_id,
+            txpool,
+            orphan_pool,
+            tx_cache,
+            tx_status_sender,
+            peer_fetch,
+            std::iter::once(id),
+        );
+    }
+}
+
+impl<P2P, DB> SharedState<P2P, DB>
+where
+    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData> + 'static,
+    DB: TxPoolDb + 'static,
+{
+    /// Queue locally-submitted transactions (e.g. from GraphQL) for
+    /// verification, exactly like a gossiped transaction, instead of
+    /// inserting them into the pool synchronously and holding the pool
+    /// lock across the full verification cost. The eventual accept/reject
+    /// outcome is reported through [`Self::tx_status_subscribe`] / the
+    /// status callback, not this call; the `Err` element of the returned
+    /// `Vec` only reports that a transaction couldn't even be queued
+    /// (backpressure or a stopped worker), handing it back so the caller
+    /// can decide how to report that.
+    pub fn insert(&self, txs: Vec<Arc<Transaction>>) -> Vec<Result<(), Transaction>> {
+        txs.into_iter()
+            .map(|tx| {
+                let tx = Arc::try_unwrap(tx).unwrap_or_else(|arc| (*arc).clone());
+                self.verification_queue.enqueue(tx, TxOrigin::Local)
+            })
+            .collect()
     }
 }
 
@@ -281,11 +653,42 @@ impl TxUpdate {
     }
 }
 
+/// Tunables for this service's own internal plumbing -- the orphan pool, the
+/// verification cache, peer-fetch, and the verification queue -- kept
+/// separate from [`Config`] since none of them affect the pool's validation
+/// rules, only its resource bounds and concurrency, and so a node can
+/// override them without needing a matching change to `Config` upstream.
+#[derive(Debug, Clone)]
+pub struct ServiceConfig {
+    pub orphan_pool: OrphanPoolConfig,
+    /// Maximum number of verification verdicts kept in
+    /// [`TxVerificationCache`].
+    pub verification_cache_capacity: usize,
+    pub peer_fetch: PeerFetchConfig,
+    /// Maximum number of transactions buffered in the
+    /// [`VerificationQueue`] awaiting verification.
+    pub verification_queue_capacity: usize,
+}
+
+impl Default for ServiceConfig {
+    fn default() -> Self {
+        Self {
+            orphan_pool: OrphanPoolConfig::default(),
+            verification_cache_capacity: 10_000,
+            peer_fetch: PeerFetchConfig::default(),
+            verification_queue_capacity: 1_000,
+        }
+    }
+}
+
 pub fn new_service<P2P, Importer, DB>(
+    chain_id: ChainId,
     config: Config,
+    service_config: ServiceConfig,
     db: DB,
     importer: Importer,
     p2p: P2P,
+    status_callback: Option<TxStatusCallback>,
 ) -> Service<P2P, DB>
 where
     Importer: BlockImporter,
@@ -296,12 +699,36 @@ where
     let gossiped_tx_stream = p2p.gossiped_transaction_events();
     let committed_block_stream = importer.block_events();
     let txpool = Arc::new(ParkingMutex::new(TxPool::new(config, db)));
+    let orphan_pool = Arc::new(ParkingMutex::new(OrphanPool::new(service_config.orphan_pool)));
+    let tx_cache = Arc::new(ParkingMutex::new(TxVerificationCache::new(
+        service_config.verification_cache_capacity,
+    )));
+    let tx_status_sender = TxStatusChange::new(100, status_callback);
+    let peer_requests = Arc::new(ParkingMutex::new(InFlightRequests::new(
+        service_config.peer_fetch.max_in_flight_per_peer,
+    )));
+    let (verification_queue, peer_fetch) = verification::spawn(
+        service_config.verification_queue_capacity,
+        chain_id,
+        txpool.clone(),
+        orphan_pool.clone(),
+        tx_cache.clone(),
+        tx_status_sender.clone(),
+        peer_requests,
+        p2p.clone(),
+        service_config.peer_fetch,
+    );
     let task = Task {
         gossiped_tx_stream,
         committed_block_stream,
         shared: SharedState {
-            tx_status_sender: TxStatusChange::new(100),
+            chain_id,
+            tx_status_sender,
             txpool,
+            orphan_pool,
+            tx_cache,
+            verification_queue,
+            peer_fetch,
             p2p,
         },
     };
@@ -309,6 +736,12 @@ where
     Service::new(task)
 }
 
+pub mod orphan_pool;
+pub mod peer_fetch;
+pub mod rejection_reason;
+pub mod tx_cache;
+pub mod verification;
+
 #[cfg(test)]
 pub mod test_helpers;
 #[cfg(test)]
@@ -0,0 +1,119 @@
+//! Mock implementations of this crate's ports, for driving [`super::new_service`]
+//! deterministically in a test instead of against a live network or database.
+
+use crate::ports::{
+    BlockImporter,
+    PeerToPeer,
+    TxPoolDb,
+};
+use fuel_core_services::stream::BoxStream;
+use fuel_core_types::{
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
+    services::{
+        block_importer::ImportResult,
+        p2p::{
+            PeerId,
+            TransactionGossipData,
+        },
+    },
+};
+use parking_lot::Mutex as ParkingMutex;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+};
+
+/// `TxPoolDb` carries no methods of its own (it's a marker bound on
+/// `Send + Sync`), so there's nothing to mock.
+pub struct MockDb;
+
+impl TxPoolDb for MockDb {}
+
+/// A [`PeerToPeer`] whose gossip stream and `request_transactions`
+/// responses are supplied up front, so a test can drive the txpool service
+/// without a live network.
+pub struct MockP2P {
+    gossip: ParkingMutex<Option<BoxStream<TransactionGossipData>>>,
+    responses: ParkingMutex<HashMap<TxId, Transaction>>,
+    broadcast: ParkingMutex<Vec<Arc<Transaction>>>,
+}
+
+impl MockP2P {
+    pub fn new(gossip: Vec<TransactionGossipData>) -> Self {
+        Self {
+            gossip: ParkingMutex::new(Some(Box::pin(tokio_stream::iter(gossip)))),
+            responses: ParkingMutex::new(HashMap::new()),
+            broadcast: ParkingMutex::new(Vec::new()),
+        }
+    }
+
+    /// Make [`PeerToPeer::request_transactions`] answer `Some(tx)` whenever
+    /// it is asked for `id`.
+    pub fn respond_with(&self, id: TxId, tx: Transaction) {
+        self.responses.lock().insert(id, tx);
+    }
+
+    /// Every transaction passed to [`PeerToPeer::broadcast_transaction`] so far.
+    pub fn broadcasted(&self) -> Vec<Arc<Transaction>> {
+        self.broadcast.lock().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl PeerToPeer for MockP2P {
+    type GossipedTransaction = TransactionGossipData;
+
+    fn broadcast_transaction(&self, transaction: Arc<Transaction>) -> anyhow::Result<()> {
+        self.broadcast.lock().push(transaction);
+        Ok(())
+    }
+
+    fn gossiped_transaction_events(&self) -> BoxStream<Self::GossipedTransaction> {
+        self.gossip
+            .lock()
+            .take()
+            .expect("gossiped_transaction_events called more than once")
+    }
+
+    async fn request_transactions(
+        &self,
+        _peer: PeerId,
+        tx_ids: Vec<TxId>,
+    ) -> anyhow::Result<Vec<Option<Transaction>>> {
+        let responses = self.responses.lock();
+        Ok(tx_ids.iter().map(|id| responses.get(id).cloned()).collect())
+    }
+
+    fn connected_peers(&self) -> Vec<PeerId> {
+        Vec::new()
+    }
+}
+
+/// A [`BlockImporter`] whose committed-block stream is supplied up front.
+pub struct MockImporter {
+    blocks: ParkingMutex<Option<BoxStream<Arc<ImportResult>>>>,
+}
+
+impl MockImporter {
+    pub fn new(blocks: Vec<Arc<ImportResult>>) -> Self {
+        Self {
+            blocks: ParkingMutex::new(Some(Box::pin(tokio_stream::iter(blocks)))),
+        }
+    }
+
+    pub fn empty() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl BlockImporter for MockImporter {
+    fn block_events(&self) -> BoxStream<Arc<ImportResult>> {
+        self.blocks
+            .lock()
+            .take()
+            .expect("block_events called more than once")
+    }
+}
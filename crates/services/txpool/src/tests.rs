@@ -0,0 +1,64 @@
+//! End-to-end coverage of [`super::new_service`] against the mocks in
+//! [`super::test_helpers`], exercising the public surface gossip and local
+//! submissions actually go through: queuing via [`super::SharedState::insert`]
+//! and observing the outcome via [`super::SharedState::tx_status_subscribe`].
+//!
+//! These assert on the pipeline actually running end to end (a submitted
+//! transaction produces *some* lifecycle event), rather than on a specific
+//! accept/reject outcome, since that depends on `TxPool`'s own verification
+//! of the submitted transaction.
+
+use super::{
+    test_helpers::{
+        MockDb,
+        MockImporter,
+        MockP2P,
+    },
+    *,
+};
+use fuel_core_types::fuel_tx::TransactionBuilder;
+use std::time::Duration;
+
+fn tx() -> Transaction {
+    TransactionBuilder::script(vec![], vec![]).finalize().into()
+}
+
+fn service() -> Service<MockP2P, MockDb> {
+    new_service(
+        ChainId::default(),
+        Config::default(),
+        ServiceConfig::default(),
+        MockDb,
+        MockImporter::empty(),
+        MockP2P::new(Vec::new()),
+        None,
+    )
+}
+
+#[tokio::test]
+async fn inserting_a_local_transaction_queues_it_and_eventually_reports_a_status() {
+    let service = service();
+    let mut status_rx = service.shared.tx_status_subscribe();
+
+    let results = service.shared.insert(vec![Arc::new(tx())]);
+    assert_eq!(results.len(), 1);
+    assert!(results[0].is_ok(), "queueing should succeed on a fresh, empty queue");
+
+    let status = tokio::time::timeout(Duration::from_secs(5), status_rx.recv())
+        .await
+        .expect("the verification worker should report a status before the timeout")
+        .expect("the status channel should not lag or close");
+    // Which lifecycle it is depends on `TxPool`'s own verification of an
+    // empty script transaction; what matters here is that one arrived at
+    // all, proving the local-submission path reaches the worker.
+    let _ = status;
+}
+
+#[tokio::test]
+async fn inserting_is_independent_per_transaction() {
+    let service = service();
+
+    let results = service.shared.insert(vec![Arc::new(tx()), Arc::new(tx())]);
+
+    assert_eq!(results.len(), 2);
+}
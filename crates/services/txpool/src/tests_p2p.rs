@@ -0,0 +1,61 @@
+//! Coverage of the [`super::test_helpers::MockP2P`] fixture itself, so the
+//! other test modules can rely on it behaving like a real [`PeerToPeer`]
+//! implementation would: a test that trusted a broken mock would pass for
+//! the wrong reason.
+//!
+//! A gossip-path test exercising [`super::Task::run`] end to end would
+//! belong here too, but that requires constructing a
+//! [`fuel_core_types::services::p2p::GossipData`] value, whose full field
+//! set isn't visible in this crate (it's defined upstream in
+//! `fuel-core-types`) — safer to leave that to `fuel-core`'s own
+//! integration tests than to guess at its shape here.
+
+use super::test_helpers::MockP2P;
+use crate::ports::PeerToPeer;
+use fuel_core_types::{
+    fuel_tx::{
+        ChainId,
+        Transaction,
+        TransactionBuilder,
+        UniqueIdentifier,
+    },
+    services::p2p::PeerId,
+};
+use std::sync::Arc;
+
+fn tx(tag: u8) -> Transaction {
+    TransactionBuilder::script(vec![tag], vec![]).finalize().into()
+}
+
+#[tokio::test]
+async fn request_transactions_answers_only_the_ids_it_was_told_to() {
+    let p2p = MockP2P::new(Vec::new());
+    let chain_id = ChainId::default();
+    let known = tx(1);
+    let known_id = known.id(&chain_id);
+    let unknown_id = tx(2).id(&chain_id);
+    p2p.respond_with(known_id, known);
+
+    let answers = p2p
+        .request_transactions(PeerId::from(vec![1u8]), vec![known_id, unknown_id])
+        .await
+        .expect("mock never errors");
+
+    assert!(answers[0].is_some());
+    assert!(answers[1].is_none());
+}
+
+#[tokio::test]
+async fn broadcast_transaction_is_recorded_for_later_assertions() {
+    let p2p = MockP2P::new(Vec::new());
+
+    p2p.broadcast_transaction(Arc::new(tx(1))).expect("mock never errors");
+
+    assert_eq!(p2p.broadcasted().len(), 1);
+}
+
+#[tokio::test]
+async fn a_fresh_mock_reports_no_connected_peers() {
+    let p2p = MockP2P::new(Vec::new());
+    assert!(p2p.connected_peers().is_empty());
+}
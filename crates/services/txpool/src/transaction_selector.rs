@@ -0,0 +1,244 @@
+use fuel_core_types::{
+    fuel_tx::{
+        input::Input,
+        TxId,
+    },
+    services::txpool::ArcPoolTx,
+};
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+
+/// What the package-ordering algorithm below actually needs from a
+/// candidate, decoupled from [`ArcPoolTx`] so that algorithm can be unit
+/// tested with plain values instead of only through the pool's own
+/// integration tests, which is all that was possible while every helper
+/// here took `ArcPoolTx` directly -- `ArcPoolTx` (`Arc<PoolTransaction>`) is
+/// only ever constructed through the pool's own checked-transaction
+/// verification path.
+#[derive(Debug, Clone, Copy)]
+struct TxScore {
+    price: u64,
+    max_gas: u64,
+}
+
+/// Selects transactions from `includable` to fill a block up to `max_gas`.
+///
+/// Candidates are scored by *package* feerate: the aggregate fee and gas of
+/// the transaction together with whichever of its in-pool ancestors haven't
+/// been included yet, rather than the transaction's own feerate in
+/// isolation. A high-fee child therefore "pulls up" a low-fee parent it
+/// depends on (fee bumping / CPFP) instead of the parent being skipped for
+/// looking unprofitable on its own. Whenever a package is chosen, every
+/// ancestor in it is emitted first, in dependency order, so a parent is
+/// never emitted after its child; the remaining candidates' scores are then
+/// recomputed, since any ancestor they shared with the chosen package is now
+/// already paid for.
+pub fn select_transactions(includable: Vec<ArcPoolTx>, max_gas: u64) -> Vec<ArcPoolTx> {
+    let by_id: HashMap<TxId, ArcPoolTx> =
+        includable.iter().map(|tx| (tx.id(), tx.clone())).collect();
+    let parents = direct_parents(&by_id);
+    let scores: HashMap<TxId, TxScore> = by_id
+        .iter()
+        .map(|(id, tx)| {
+            (
+                *id,
+                TxScore {
+                    price: tx.price(),
+                    max_gas: tx.max_gas(),
+                },
+            )
+        })
+        .collect();
+    let ancestors = transitive_ancestors(&parents);
+
+    let mut remaining: HashSet<TxId> = by_id.keys().copied().collect();
+    let mut selected = Vec::with_capacity(by_id.len());
+    let mut used_gas: u64 = 0;
+
+    while let Some((package, package_gas)) =
+        best_package(&scores, &ancestors, &remaining, max_gas.saturating_sub(used_gas))
+    {
+        used_gas += package_gas;
+        for id in package {
+            remaining.remove(&id);
+            selected.push(by_id[&id].clone());
+        }
+    }
+
+    selected
+}
+
+/// Direct in-pool parents of each candidate: the ids of the transactions
+/// (also present in `by_id`) that produced the coins it spends.
+fn direct_parents(by_id: &HashMap<TxId, ArcPoolTx>) -> HashMap<TxId, HashSet<TxId>> {
+    by_id
+        .iter()
+        .map(|(id, tx)| {
+            let parents = tx
+                .inputs()
+                .iter()
+                .filter_map(Input::utxo_id)
+                .map(|utxo_id| *utxo_id.tx_id())
+                .filter(|parent| by_id.contains_key(parent))
+                .collect();
+            (*id, parents)
+        })
+        .collect()
+}
+
+/// Full transitive ancestor set of every candidate, memoized.
+fn transitive_ancestors(parents: &HashMap<TxId, HashSet<TxId>>) -> HashMap<TxId, HashSet<TxId>> {
+    let mut memo = HashMap::new();
+    for id in parents.keys() {
+        resolve_ancestors(*id, parents, &mut memo);
+    }
+    memo
+}
+
+fn resolve_ancestors(
+    id: TxId,
+    parents: &HashMap<TxId, HashSet<TxId>>,
+    memo: &mut HashMap<TxId, HashSet<TxId>>,
+) -> HashSet<TxId> {
+    if let Some(cached) = memo.get(&id) {
+        return cached.clone();
+    }
+    // Guard against a cycle (shouldn't happen for a valid pool) rather than
+    // recursing forever.
+    memo.insert(id, HashSet::new());
+
+    let mut ancestors = HashSet::new();
+    if let Some(direct) = parents.get(&id) {
+        for &parent in direct {
+            ancestors.insert(parent);
+            ancestors.extend(resolve_ancestors(parent, parents, memo));
+        }
+    }
+
+    memo.insert(id, ancestors.clone());
+    ancestors
+}
+
+/// Among `remaining`, find the package (a candidate plus its not-yet-
+/// included ancestors) with the best aggregate feerate that fits within
+/// `gas_budget`, returned in ancestors-first order together with its total
+/// gas. `None` once nothing remaining fits.
+fn best_package(
+    scores: &HashMap<TxId, TxScore>,
+    ancestors: &HashMap<TxId, HashSet<TxId>>,
+    remaining: &HashSet<TxId>,
+    gas_budget: u64,
+) -> Option<(Vec<TxId>, u64)> {
+    let mut best: Option<(Vec<TxId>, u64, u128)> = None;
+
+    for &id in remaining {
+        // Ancestors of `id` sorted by how many ancestors they themselves
+        // have (their full ancestor sets) is a valid topological order: an
+        // ancestor's ancestor set is always a strict subset of its
+        // descendant's.
+        let mut package: Vec<TxId> = ancestors[&id]
+            .iter()
+            .copied()
+            .filter(|ancestor| remaining.contains(ancestor))
+            .collect();
+        package.sort_by_key(|member| ancestors[member].len());
+        package.push(id);
+
+        let package_gas: u64 = package.iter().map(|member| scores[member].max_gas).sum();
+        if package_gas == 0 || package_gas > gas_budget {
+            continue;
+        }
+
+        let package_fee: u128 = package
+            .iter()
+            .map(|member| scores[member].price as u128 * scores[member].max_gas as u128)
+            .sum();
+        let score = package_fee / package_gas as u128;
+
+        let is_better = match &best {
+            Some((_, _, best_score)) => score > *best_score,
+            None => true,
+        };
+        if is_better {
+            best = Some((package, package_gas, score));
+        }
+    }
+
+    best.map(|(package, gas, _)| (package, gas))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id(tag: u8) -> TxId {
+        TxId::from([tag; 32])
+    }
+
+    fn score(price: u64, max_gas: u64) -> TxScore {
+        TxScore { price, max_gas }
+    }
+
+    /// A lone high-fee child pulls its unprofitable-looking parent into the
+    /// same package (fee bumping / CPFP), instead of the parent being
+    /// skipped for scoring worse on its own.
+    #[test]
+    fn a_high_fee_child_pulls_its_low_fee_parent_into_the_same_package() {
+        let parent = id(1);
+        let child = id(2);
+        let scores = HashMap::from([(parent, score(1, 100)), (child, score(100, 10))]);
+        let parents = HashMap::from([(parent, HashSet::new()), (child, HashSet::from([parent]))]);
+        let ancestors = transitive_ancestors(&parents);
+        let remaining = HashSet::from([parent, child]);
+
+        let (package, gas) = best_package(&scores, &ancestors, &remaining, 1_000)
+            .expect("both candidates together fit the budget");
+
+        assert_eq!(package, vec![parent, child]);
+        assert_eq!(gas, 110);
+    }
+
+    #[test]
+    fn a_package_that_does_not_fit_the_gas_budget_is_skipped() {
+        let only = id(1);
+        let scores = HashMap::from([(only, score(10, 100))]);
+        let parents = HashMap::from([(only, HashSet::new())]);
+        let ancestors = transitive_ancestors(&parents);
+        let remaining = HashSet::from([only]);
+
+        assert!(best_package(&scores, &ancestors, &remaining, 99).is_none());
+    }
+
+    #[test]
+    fn among_independent_candidates_the_better_feerate_package_wins() {
+        let cheap = id(1);
+        let rich = id(2);
+        let scores = HashMap::from([(cheap, score(1, 100)), (rich, score(10, 100))]);
+        let parents = HashMap::from([(cheap, HashSet::new()), (rich, HashSet::new())]);
+        let ancestors = transitive_ancestors(&parents);
+        let remaining = HashSet::from([cheap, rich]);
+
+        let (package, _) = best_package(&scores, &ancestors, &remaining, 100)
+            .expect("the budget fits exactly one candidate");
+
+        assert_eq!(package, vec![rich]);
+    }
+
+    #[test]
+    fn resolve_ancestors_returns_the_full_transitive_set() {
+        let grandparent = id(1);
+        let parent = id(2);
+        let child = id(3);
+        let parents = HashMap::from([
+            (grandparent, HashSet::new()),
+            (parent, HashSet::from([grandparent])),
+            (child, HashSet::from([parent])),
+        ]);
+
+        let ancestors = resolve_ancestors(child, &parents, &mut HashMap::new());
+
+        assert_eq!(ancestors, HashSet::from([grandparent, parent]));
+    }
+}
@@ -0,0 +1,206 @@
+use crate::rejection_reason::RejectionReason;
+use fuel_core_types::fuel_tx::TxId;
+use std::collections::{
+    HashMap,
+    VecDeque,
+};
+
+/// The verified outcome of a transaction, cached so a re-gossiped tx
+/// doesn't have to be re-validated from scratch.
+#[derive(Debug, Clone, PartialEq)]
+pub enum VerificationOutcome {
+    /// The transaction was accepted, carrying the fee/gas metadata computed
+    /// during verification.
+    Accepted { fee: u64, gas: u64 },
+    /// The transaction was rejected for the given reason.
+    Rejected(RejectionReason),
+}
+
+struct CacheEntry {
+    outcome: VerificationOutcome,
+    params_version: u64,
+}
+
+/// Caches the outcome of verifying a transaction, keyed by [`TxId`], so
+/// that the same transaction offered by many gossiping peers is only
+/// validated once. Entries are tagged with the consensus parameters
+/// version they were checked against; bumping the version via
+/// [`Self::bump_params_version`] makes every previously cached entry stale
+/// without having to walk the whole cache. Bounded by an LRU policy.
+pub struct TxVerificationCache {
+    capacity: usize,
+    current_version: u64,
+    last_observed_consensus_parameters_version: Option<u32>,
+    entries: HashMap<TxId, CacheEntry>,
+    order: VecDeque<TxId>,
+}
+
+impl TxVerificationCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            current_version: 0,
+            last_observed_consensus_parameters_version: None,
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// Look up the cached outcome for `id`, if any, checked against the
+    /// current consensus parameters version. A stale entry (checked under a
+    /// version that's no longer current) is treated as a miss and evicted.
+    pub fn get(&mut self, id: &TxId) -> Option<VerificationOutcome> {
+        let stale = match self.entries.get(id) {
+            Some(entry) => entry.params_version != self.current_version,
+            None => return None,
+        };
+
+        if stale {
+            self.remove(id);
+            return None;
+        }
+
+        self.touch(id);
+        self.entries.get(id).map(|entry| entry.outcome.clone())
+    }
+
+    /// Record the outcome of verifying `id` against the current consensus
+    /// parameters version, evicting the least-recently-used entry if the
+    /// cache is full.
+    pub fn insert(&mut self, id: TxId, outcome: VerificationOutcome) {
+        if !self.entries.contains_key(&id) {
+            if self.entries.len() >= self.capacity {
+                if let Some(oldest) = self.order.pop_front() {
+                    self.entries.remove(&oldest);
+                }
+            }
+            self.order.push_back(id);
+        } else {
+            self.touch(&id);
+        }
+
+        self.entries.insert(
+            id,
+            CacheEntry {
+                outcome,
+                params_version: self.current_version,
+            },
+        );
+    }
+
+    /// Mark every cached verdict as checked against a stale consensus
+    /// parameters version outright.
+    fn bump_params_version(&mut self) {
+        self.current_version = self.current_version.wrapping_add(1);
+    }
+
+    /// Report the consensus parameters version of the block just committed,
+    /// invalidating the whole cache only if it actually differs from the
+    /// last one observed. Transactions typically sit in the mempool across
+    /// many blocks, so bumping unconditionally on every committed block (as
+    /// opposed to only on an actual consensus parameters upgrade) would
+    /// flush the cache long before most re-gossip traffic could ever hit it.
+    pub fn observe_consensus_parameters_version(&mut self, version: u32) {
+        if self.last_observed_consensus_parameters_version != Some(version) {
+            self.last_observed_consensus_parameters_version = Some(version);
+            self.bump_params_version();
+        }
+    }
+
+    /// Discard every cached verdict outright, keeping the configured
+    /// capacity.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+
+    fn remove(&mut self, id: &TxId) {
+        self.entries.remove(id);
+        self.order.retain(|queued| queued != id);
+    }
+
+    fn touch(&mut self, id: &TxId) {
+        self.order.retain(|queued| queued != id);
+        self.order.push_back(*id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accepted() -> VerificationOutcome {
+        VerificationOutcome::Accepted { fee: 1, gas: 1 }
+    }
+
+    #[test]
+    fn a_fresh_id_is_a_miss() {
+        let mut cache = TxVerificationCache::new(10);
+        assert_eq!(cache.get(&TxId::from([1u8; 32])), None);
+    }
+
+    #[test]
+    fn an_inserted_entry_is_returned_until_the_params_version_moves_on() {
+        let mut cache = TxVerificationCache::new(10);
+        let id = TxId::from([1u8; 32]);
+        cache.insert(id, accepted());
+        assert_eq!(cache.get(&id), Some(accepted()));
+
+        cache.observe_consensus_parameters_version(1);
+        assert_eq!(cache.get(&id), None);
+    }
+
+    #[test]
+    fn bumping_the_consensus_parameters_version_invalidates_existing_entries() {
+        let mut cache = TxVerificationCache::new(10);
+        let id = TxId::from([1u8; 32]);
+        cache.insert(id, accepted());
+
+        cache.observe_consensus_parameters_version(1);
+
+        assert_eq!(cache.get(&id), None);
+    }
+
+    #[test]
+    fn observing_the_same_consensus_parameters_version_twice_keeps_entries() {
+        let mut cache = TxVerificationCache::new(10);
+        let id = TxId::from([1u8; 32]);
+        cache.observe_consensus_parameters_version(1);
+        cache.insert(id, accepted());
+
+        cache.observe_consensus_parameters_version(1);
+
+        assert_eq!(cache.get(&id), Some(accepted()));
+    }
+
+    #[test]
+    fn inserting_past_capacity_evicts_the_least_recently_used_entry() {
+        let mut cache = TxVerificationCache::new(2);
+        let first = TxId::from([1u8; 32]);
+        let second = TxId::from([2u8; 32]);
+        let third = TxId::from([3u8; 32]);
+
+        cache.insert(first, accepted());
+        cache.insert(second, accepted());
+        // Touches `first`, so `second` becomes the least recently used entry.
+        cache.get(&first);
+        cache.insert(third, accepted());
+
+        assert_eq!(cache.get(&first), Some(accepted()));
+        assert_eq!(cache.get(&second), None);
+        assert_eq!(cache.get(&third), Some(accepted()));
+    }
+
+    #[test]
+    fn clear_discards_every_entry_but_keeps_the_cache_usable() {
+        let mut cache = TxVerificationCache::new(10);
+        let id = TxId::from([1u8; 32]);
+        cache.insert(id, accepted());
+
+        cache.clear();
+
+        assert_eq!(cache.get(&id), None);
+        cache.insert(id, accepted());
+        assert_eq!(cache.get(&id), Some(accepted()));
+    }
+}
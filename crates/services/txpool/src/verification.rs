@@ -0,0 +1,319 @@
+use super::{
+    orphan_pool::OrphanPool,
+    peer_fetch::{
+        InFlightRequests,
+        PeerFetch,
+        PeerFetchConfig,
+    },
+    tx_cache::TxVerificationCache,
+    TxStatusChange,
+};
+use crate::{
+    ports::{
+        PeerToPeer,
+        TxPoolDb,
+    },
+    TxPool,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        ChainId,
+        Transaction,
+        TxId,
+    },
+    services::p2p::{
+        PeerId,
+        TransactionGossipData,
+    },
+};
+use parking_lot::Mutex as ParkingMutex;
+use std::sync::{
+    atomic::{
+        AtomicUsize,
+        Ordering,
+    },
+    Arc,
+};
+use tokio::sync::mpsc;
+
+/// Control messages accepted by the verification worker spawned by
+/// [`spawn`]. `Suspend`/`Resume` let a caller (e.g. block-import during a
+/// heavy resync) pause verification without tearing down the worker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerifyCommand {
+    Start,
+    Suspend,
+    Resume,
+    Stop,
+}
+
+/// Where a transaction fed into [`super::process_verified`] came from,
+/// which decides who (if anyone) to ask for a missing parent on an
+/// unknown-input rejection, and whether a successful insertion should be
+/// rebroadcast to the network.
+#[derive(Debug, Clone)]
+pub(crate) enum TxOrigin {
+    /// Received by gossip from this peer; ask them for a missing parent on
+    /// an unknown-input rejection. Never rebroadcast — the network already
+    /// has it.
+    Gossip(PeerId),
+    /// Submitted locally (e.g. via GraphQL). No peer to ask for a missing
+    /// parent, but broadcast it on successful insertion.
+    Local,
+    /// Re-submitted by [`promote_orphans`] once every input it was waiting
+    /// on arrived. No peer to ask if it turns out to be missing a
+    /// *different* input (it's simply re-orphaned), and no rebroadcast,
+    /// since it was already broadcast or gossiped the first time it was
+    /// seen.
+    Orphan,
+}
+
+impl TxOrigin {
+    fn peer(&self) -> Option<PeerId> {
+        match self {
+            Self::Gossip(peer) => Some(peer.clone()),
+            Self::Local | Self::Orphan => None,
+        }
+    }
+
+    fn should_broadcast(&self) -> bool {
+        matches!(self, Self::Local)
+    }
+}
+
+/// Handle used to push gossiped/locally-submitted transactions into the
+/// verification pipeline and to control the worker's run state, without
+/// holding the pool lock for the duration of verification.
+#[derive(Clone)]
+pub struct VerificationQueue {
+    work: mpsc::Sender<(Transaction, TxOrigin)>,
+    control: mpsc::UnboundedSender<VerifyCommand>,
+    depth: Arc<AtomicUsize>,
+}
+
+impl VerificationQueue {
+    /// Current number of transactions waiting to be verified.
+    pub fn len(&self) -> usize {
+        self.depth.load(Ordering::Relaxed)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Queue `tx` for verification, tagged with where it came from (see
+    /// [`TxOrigin`]), so a genuinely-unknown input can be fetched from the
+    /// gossiping peer if insertion fails on it. Returns the transaction back
+    /// to the caller if the queue is full or the worker has stopped, so
+    /// callers can decide how to report backpressure.
+    pub(crate) fn enqueue(&self, tx: Transaction, origin: TxOrigin) -> Result<(), Transaction> {
+        self.depth.fetch_add(1, Ordering::Relaxed);
+        self.work.try_send((tx, origin)).map_err(|e| {
+            self.depth.fetch_sub(1, Ordering::Relaxed);
+            match e {
+                mpsc::error::TrySendError::Full((tx, _)) => tx,
+                mpsc::error::TrySendError::Closed((tx, _)) => tx,
+            }
+        })
+    }
+
+    pub fn suspend(&self) {
+        let _ = self.control.send(VerifyCommand::Suspend);
+    }
+
+    pub fn resume(&self) {
+        let _ = self.control.send(VerifyCommand::Resume);
+    }
+
+    pub fn stop(&self) {
+        let _ = self.control.send(VerifyCommand::Stop);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_tx::TransactionBuilder;
+
+    fn tx() -> Transaction {
+        TransactionBuilder::script(vec![], vec![]).finalize().into()
+    }
+
+    fn queue(capacity: usize) -> (VerificationQueue, mpsc::Receiver<(Transaction, TxOrigin)>) {
+        let (work, work_rx) = mpsc::channel(capacity);
+        let (control, _control_rx) = mpsc::unbounded_channel();
+        (
+            VerificationQueue {
+                work,
+                control,
+                depth: Arc::new(AtomicUsize::new(0)),
+            },
+            work_rx,
+        )
+    }
+
+    #[test]
+    fn enqueue_on_a_queue_with_room_succeeds_and_bumps_depth() {
+        let (queue, _rx) = queue(1);
+        assert!(queue.enqueue(tx(), TxOrigin::Local).is_ok());
+        assert_eq!(queue.len(), 1);
+        assert!(!queue.is_empty());
+    }
+
+    #[test]
+    fn enqueue_past_capacity_hands_the_transaction_back_and_leaves_depth_unchanged() {
+        let (queue, _rx) = queue(1);
+        queue.enqueue(tx(), TxOrigin::Local).expect("first enqueue has room");
+
+        let result = queue.enqueue(tx(), TxOrigin::Local);
+
+        assert!(result.is_err());
+        assert_eq!(queue.len(), 1);
+    }
+
+    #[test]
+    fn enqueue_after_the_worker_is_gone_hands_the_transaction_back() {
+        let (queue, rx) = queue(1);
+        drop(rx);
+
+        let result = queue.enqueue(tx(), TxOrigin::Local);
+
+        assert!(result.is_err());
+        assert_eq!(queue.len(), 0);
+    }
+
+    #[test]
+    fn only_a_gossiped_origin_carries_a_peer_to_ask_for_a_missing_parent() {
+        let peer = PeerId::from(vec![1u8]);
+        assert_eq!(TxOrigin::Gossip(peer.clone()).peer(), Some(peer));
+        assert_eq!(TxOrigin::Local.peer(), None);
+        assert_eq!(TxOrigin::Orphan.peer(), None);
+    }
+
+    #[test]
+    fn only_a_local_submission_is_rebroadcast_on_acceptance() {
+        assert!(TxOrigin::Local.should_broadcast());
+        assert!(!TxOrigin::Gossip(PeerId::from(vec![1u8])).should_broadcast());
+        assert!(!TxOrigin::Orphan.should_broadcast());
+    }
+}
+
+/// Spawn the verification worker and return a handle to it. The worker
+/// pulls transactions off `work`, inserts the ones that pass verification
+/// into the pool under a short-held lock, reports failures through
+/// `tx_status_sender` exactly as an inline `insert` would have, and, for a
+/// rejection that's actually caused by an unknown input, pulls the missing
+/// parent from the peer that gossiped the transaction (see
+/// [`PeerFetch::fetch`]).
+pub fn spawn<P2P, DB>(
+    capacity: usize,
+    chain_id: ChainId,
+    txpool: Arc<ParkingMutex<TxPool<DB>>>,
+    orphan_pool: Arc<ParkingMutex<OrphanPool>>,
+    tx_cache: Arc<ParkingMutex<TxVerificationCache>>,
+    tx_status_sender: TxStatusChange,
+    in_flight: Arc<ParkingMutex<InFlightRequests>>,
+    p2p: Arc<P2P>,
+    peer_fetch_config: PeerFetchConfig,
+) -> (VerificationQueue, PeerFetch<P2P>)
+where
+    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData> + 'static,
+    DB: TxPoolDb + 'static,
+{
+    let (work_tx, mut work_rx) = mpsc::channel(capacity);
+    let (control_tx, mut control_rx) = mpsc::unbounded_channel();
+    let depth = Arc::new(AtomicUsize::new(0));
+    let worker_depth = depth.clone();
+
+    let queue = VerificationQueue {
+        work: work_tx,
+        control: control_tx,
+        depth,
+    };
+    let peer_fetch = PeerFetch {
+        p2p,
+        in_flight,
+        verification_queue: queue.clone(),
+        config: peer_fetch_config,
+    };
+    let worker_peer_fetch = peer_fetch.clone();
+
+    tokio::spawn(async move {
+        let mut suspended = false;
+        loop {
+            tokio::select! {
+                biased;
+                command = control_rx.recv() => {
+                    match command {
+                        Some(VerifyCommand::Start) | Some(VerifyCommand::Resume) => {
+                            suspended = false;
+                        }
+                        Some(VerifyCommand::Suspend) => {
+                            suspended = true;
+                        }
+                        Some(VerifyCommand::Stop) | None => break,
+                    }
+                }
+                next = work_rx.recv(), if !suspended => {
+                    match next {
+                        Some((tx, origin)) => {
+                            worker_depth.fetch_sub(1, Ordering::Relaxed);
+                            super::process_verified(
+                                &chain_id,
+                                &txpool,
+                                &orphan_pool,
+                                &tx_cache,
+                                &tx_status_sender,
+                                tx,
+                                origin,
+                                &worker_peer_fetch,
+                            );
+                        }
+                        None => break,
+                    }
+                }
+            }
+        }
+    });
+
+    (queue, peer_fetch)
+}
+
+/// Recursively promote orphans unblocked by `newly_available`, re-submitting
+/// each one through [`super::insert_or_reject`] — the same cache check,
+/// rejection reporting and re-orphaning every other insertion path gets —
+/// instead of inserting into the pool raw. Iterative rather than recursing
+/// through [`super::process_verified`], so a long dependency chain unblocks
+/// without growing the call stack.
+pub(crate) fn promote_orphans<P2P, DB>(
+    chain_id: &ChainId,
+    txpool: &Arc<ParkingMutex<TxPool<DB>>>,
+    orphan_pool: &Arc<ParkingMutex<OrphanPool>>,
+    tx_cache: &Arc<ParkingMutex<TxVerificationCache>>,
+    tx_status_sender: &TxStatusChange,
+    peer_fetch: &PeerFetch<P2P>,
+    newly_available: impl Iterator<Item = TxId>,
+) where
+    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData> + 'static,
+    DB: TxPoolDb,
+{
+    let mut queue: std::collections::VecDeque<TxId> = newly_available.collect();
+
+    while let Some(id) = queue.pop_front() {
+        let ready = orphan_pool.lock().resolve(&id);
+        for tx in ready {
+            if let Some(accepted_id) = super::insert_or_reject(
+                chain_id,
+                txpool,
+                orphan_pool,
+                tx_cache,
+                tx_status_sender,
+                tx,
+                &TxOrigin::Orphan,
+                peer_fetch,
+            ) {
+                queue.push_back(accepted_id);
+            }
+        }
+    }
+}